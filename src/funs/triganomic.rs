@@ -5,7 +5,7 @@
  * (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
  */
 use num::cast::{NumCast, cast};
-use angle::Radians;
+use angle::{Radians, Degrees};
 use vec::{Vec3, Vec2, Vec4};
 
 ///
@@ -17,16 +17,29 @@ priv trait Trig<T> {
     pure fn sin() -> T;
     pure fn cos() -> T;
     pure fn tan() -> T;
+    pure fn sincos() -> (T, T) { (self.sin(), self.cos()) }
 }
 
 #[inline(always)] pub pure fn sin<T:Trig<R>, R>(theta: &T) -> R { theta.sin() }
 #[inline(always)] pub pure fn cos<T:Trig<R>, R>(theta: &T) -> R { theta.cos() }
 #[inline(always)] pub pure fn tan<T:Trig<R>, R>(theta: &T) -> R { theta.tan() }
 
+/// Computes `(sin(theta), cos(theta))` in one call. `Radians`, `Degrees` and
+/// vectors of `Radians` take both values from a single `f64::sin_cos` call per
+/// component; types using the trait's default `sincos` (such as vectors of
+/// `Degrees`) still call `sin` and `cos` separately.
+#[inline(always)] pub pure fn sincos<T:Trig<R>, R>(theta: &T) -> (R, R) { theta.sincos() }
+
 priv impl<T:Copy Num NumCast> Radians<T>: Trig<T> {
     #[inline(always)] pure fn sin() -> T { cast(f64::sin(cast(*self))) }
     #[inline(always)] pure fn cos() -> T { cast(f64::cos(cast(*self))) }
     #[inline(always)] pure fn tan() -> T { cast(f64::tan(cast(*self))) }
+
+    #[inline(always)]
+    pure fn sincos() -> (T, T) {
+        let (s, c) = f64::sin_cos(cast(*self));
+        (cast(s), cast(c))
+    }
 }
 
 pub impl<T:Copy Num NumCast> Vec2<Radians<T>>: Trig<Vec2<T>>  {
@@ -47,6 +60,13 @@ pub impl<T:Copy Num NumCast> Vec2<Radians<T>>: Trig<Vec2<T>>  {
         Vec2::new(tan(&self[0]),
                   tan(&self[1]))
     }
+
+    #[inline(always)]
+    pure fn sincos() -> (Vec2<T>, Vec2<T>) {
+        let (s0, c0) = sincos(&self[0]);
+        let (s1, c1) = sincos(&self[1]);
+        (Vec2::new(s0, s1), Vec2::new(c0, c1))
+    }
 }
 
 pub impl<T:Copy Num NumCast> Vec3<Radians<T>>: Trig<Vec3<T>>  {
@@ -70,6 +90,95 @@ pub impl<T:Copy Num NumCast> Vec3<Radians<T>>: Trig<Vec3<T>>  {
                   tan(&self[1]),
                   tan(&self[2]))
     }
+
+    #[inline(always)]
+    pure fn sincos() -> (Vec3<T>, Vec3<T>) {
+        let (s0, c0) = sincos(&self[0]);
+        let (s1, c1) = sincos(&self[1]);
+        let (s2, c2) = sincos(&self[2]);
+        (Vec3::new(s0, s1, s2), Vec3::new(c0, c1, c2))
+    }
+}
+
+priv impl<T:Copy Num NumCast> Degrees<T>: Trig<T> {
+    #[inline(always)] pure fn sin() -> T { cast(f64::sin(cast(*self) * f64::consts::pi / 180f64)) }
+    #[inline(always)] pure fn cos() -> T { cast(f64::cos(cast(*self) * f64::consts::pi / 180f64)) }
+    #[inline(always)] pure fn tan() -> T { cast(f64::tan(cast(*self) * f64::consts::pi / 180f64)) }
+
+    #[inline(always)]
+    pure fn sincos() -> (T, T) {
+        let (s, c) = f64::sin_cos(cast(*self) * f64::consts::pi / 180f64);
+        (cast(s), cast(c))
+    }
+}
+
+pub impl<T:Copy Num NumCast> Vec2<Degrees<T>>: Trig<Vec2<T>>  {
+    #[inline(always)]
+    pure fn sin() -> Vec2<T> {
+        Vec2::new(sin(&self[0]),
+                  sin(&self[1]))
+    }
+
+    #[inline(always)]
+    pure fn cos() -> Vec2<T> {
+        Vec2::new(cos(&self[0]),
+                  cos(&self[1]))
+    }
+
+    #[inline(always)]
+    pure fn tan() -> Vec2<T> {
+        Vec2::new(tan(&self[0]),
+                  tan(&self[1]))
+    }
+}
+
+pub impl<T:Copy Num NumCast> Vec3<Degrees<T>>: Trig<Vec3<T>>  {
+    #[inline(always)]
+    pure fn sin() -> Vec3<T> {
+        Vec3::new(sin(&self[0]),
+                  sin(&self[1]),
+                  sin(&self[2]))
+    }
+
+    #[inline(always)]
+    pure fn cos() -> Vec3<T> {
+        Vec3::new(cos(&self[0]),
+                  cos(&self[1]),
+                  cos(&self[2]))
+    }
+
+    #[inline(always)]
+    pure fn tan() -> Vec3<T> {
+        Vec3::new(tan(&self[0]),
+                  tan(&self[1]),
+                  tan(&self[2]))
+    }
+}
+
+pub impl<T:Copy Num NumCast> Vec4<Degrees<T>>: Trig<Vec4<T>>  {
+    #[inline(always)]
+    pure fn sin() -> Vec4<T> {
+        Vec4::new(sin(&self[0]),
+                  sin(&self[1]),
+                  sin(&self[2]),
+                  sin(&self[3]))
+    }
+
+    #[inline(always)]
+    pure fn cos() -> Vec4<T> {
+        Vec4::new(cos(&self[0]),
+                  cos(&self[1]),
+                  cos(&self[2]),
+                  cos(&self[3]))
+    }
+
+    #[inline(always)]
+    pure fn tan() -> Vec4<T> {
+        Vec4::new(tan(&self[0]),
+                  tan(&self[1]),
+                  tan(&self[2]),
+                  tan(&self[3]))
+    }
 }
 
 pub impl<T:Copy Num NumCast> Vec4<Radians<T>>: Trig<Vec4<T>>  {
@@ -96,6 +205,15 @@ pub impl<T:Copy Num NumCast> Vec4<Radians<T>>: Trig<Vec4<T>>  {
                   tan(&self[2]),
                   tan(&self[3]))
     }
+
+    #[inline(always)]
+    pure fn sincos() -> (Vec4<T>, Vec4<T>) {
+        let (s0, c0) = sincos(&self[0]);
+        let (s1, c1) = sincos(&self[1]);
+        let (s2, c2) = sincos(&self[2]);
+        let (s3, c3) = sincos(&self[3]);
+        (Vec4::new(s0, s1, s2, s3), Vec4::new(c0, c1, c2, c3))
+    }
 }
 
 ///
@@ -131,6 +249,38 @@ pub impl float: InvTrig {
     #[inline(always)] pure fn atan() -> Radians<float> { Radians(f64::atan(cast(self)).to_float()) }
 }
 
+///
+/// The two-argument arctangent
+///
+/// http://en.wikipedia.org/wiki/Atan2
+///
+pub trait Atan2 {
+    pure fn atan2(x: self) -> Radians<self>;
+}
+
+#[inline(always)] pub pure fn atan2<T:Atan2>(y: &T, x: &T) -> Radians<T> { (*y).atan2(*x) }
+
+pub impl f32: Atan2 {
+    #[inline(always)] pure fn atan2(x: f32) -> Radians<f32> { Radians(f32::atan2(self, x)) }
+}
+
+pub impl f64: Atan2 {
+    #[inline(always)] pure fn atan2(x: f64) -> Radians<f64> { Radians(f64::atan2(self, x)) }
+}
+
+pub impl float: Atan2 {
+    #[inline(always)] pure fn atan2(x: float) -> Radians<float> { Radians(f64::atan2(cast(self), cast(x)).to_float()) }
+}
+
+/// Convenience for recovering the angle of a 2D vector, equivalent to
+/// `atan2(&v.y, &v.x)`.
+pub impl<T:Copy Num NumCast Atan2> Vec2<T> {
+    #[inline(always)]
+    pure fn atan2() -> Radians<T> {
+        atan2(&self[1], &self[0])
+    }
+}
+
 // TODO: figure out how to merge with InvTrig
 pub trait InvTrigV<T> {
     pure fn asin() -> T;
@@ -218,31 +368,43 @@ pub trait Hyp {
     pure fn sinh() -> self;
     pure fn cosh() -> self;
     pure fn tanh() -> self;
-    // pure fn asinh() -> self;
-    // pure fn acosh() -> self;
-    // pure fn atanh() -> self;
+    pure fn asinh() -> self;
+    pure fn acosh() -> self;
+    pure fn atanh() -> self;
 }
 
 #[inline(always)] pub pure fn sinh<T:Hyp>(x: &T) -> T { x.sinh() }
 #[inline(always)] pub pure fn cosh<T:Hyp>(x: &T) -> T { x.cosh() }
 #[inline(always)] pub pure fn tanh<T:Hyp>(x: &T) -> T { x.tanh() }
+#[inline(always)] pub pure fn asinh<T:Hyp>(x: &T) -> T { x.asinh() }
+#[inline(always)] pub pure fn acosh<T:Hyp>(x: &T) -> T { x.acosh() }
+#[inline(always)] pub pure fn atanh<T:Hyp>(x: &T) -> T { x.atanh() }
 
 pub impl f32: Hyp {
     #[inline(always)] pure fn sinh() -> f32 { f32::sinh(self) }
     #[inline(always)] pure fn cosh() -> f32 { f32::cosh(self) }
     #[inline(always)] pure fn tanh() -> f32 { f32::tanh(self) }
+    #[inline(always)] pure fn asinh() -> f32 { f32::ln(self + f32::sqrt(self * self + 1f32)) }
+    #[inline(always)] pure fn acosh() -> f32 { f32::ln(self + f32::sqrt(self * self - 1f32)) }
+    #[inline(always)] pure fn atanh() -> f32 { 0.5f32 * f32::ln((1f32 + self) / (1f32 - self)) }
 }
 
 pub impl f64: Hyp {
     #[inline(always)] pure fn sinh() -> f64 { f64::sinh(self) }
     #[inline(always)] pure fn cosh() -> f64 { f64::cosh(self) }
     #[inline(always)] pure fn tanh() -> f64 { f64::tanh(self) }
+    #[inline(always)] pure fn asinh() -> f64 { f64::ln(self + f64::sqrt(self * self + 1f64)) }
+    #[inline(always)] pure fn acosh() -> f64 { f64::ln(self + f64::sqrt(self * self - 1f64)) }
+    #[inline(always)] pure fn atanh() -> f64 { 0.5f64 * f64::ln((1f64 + self) / (1f64 - self)) }
 }
 
 pub impl float: Hyp {
     #[inline(always)] pure fn sinh() -> float { cast(f64::sinh(cast(self))) }
     #[inline(always)] pure fn cosh() -> float { cast(f64::cosh(cast(self))) }
     #[inline(always)] pure fn tanh() -> float { cast(f64::tanh(cast(self))) }
+    #[inline(always)] pure fn asinh() -> float { cast(f64::ln(cast::<float, f64>(self) + f64::sqrt(cast::<float, f64>(self) * cast::<float, f64>(self) + 1f64))) }
+    #[inline(always)] pure fn acosh() -> float { cast(f64::ln(cast::<float, f64>(self) + f64::sqrt(cast::<float, f64>(self) * cast::<float, f64>(self) - 1f64))) }
+    #[inline(always)] pure fn atanh() -> float { cast(0.5f64 * f64::ln((1f64 + cast::<float, f64>(self)) / (1f64 - cast::<float, f64>(self)))) }
 }
 
 pub impl <T:Copy Hyp> Vec2<T>: Hyp {
@@ -251,18 +413,36 @@ pub impl <T:Copy Hyp> Vec2<T>: Hyp {
         Vec2::new(sinh(&self[0]),
                   sinh(&self[1]))
     }
-    
+
     #[inline(always)]
     pure fn cosh() -> Vec2<T> {
         Vec2::new(cosh(&self[0]),
                   cosh(&self[1]))
     }
-    
+
     #[inline(always)]
     pure fn tanh() -> Vec2<T> {
         Vec2::new(tanh(&self[0]),
                   tanh(&self[1]))
     }
+
+    #[inline(always)]
+    pure fn asinh() -> Vec2<T> {
+        Vec2::new(asinh(&self[0]),
+                  asinh(&self[1]))
+    }
+
+    #[inline(always)]
+    pure fn acosh() -> Vec2<T> {
+        Vec2::new(acosh(&self[0]),
+                  acosh(&self[1]))
+    }
+
+    #[inline(always)]
+    pure fn atanh() -> Vec2<T> {
+        Vec2::new(atanh(&self[0]),
+                  atanh(&self[1]))
+    }
 }
 
 pub impl <T:Copy Hyp> Vec3<T>: Hyp {
@@ -272,20 +452,41 @@ pub impl <T:Copy Hyp> Vec3<T>: Hyp {
                   sinh(&self[1]),
                   sinh(&self[2]))
     }
-    
+
     #[inline(always)]
     pure fn cosh() -> Vec3<T> {
         Vec3::new(cosh(&self[0]),
                   cosh(&self[1]),
                   cosh(&self[2]))
     }
-    
+
     #[inline(always)]
     pure fn tanh() -> Vec3<T> {
         Vec3::new(tanh(&self[0]),
                   tanh(&self[1]),
                   tanh(&self[2]))
     }
+
+    #[inline(always)]
+    pure fn asinh() -> Vec3<T> {
+        Vec3::new(asinh(&self[0]),
+                  asinh(&self[1]),
+                  asinh(&self[2]))
+    }
+
+    #[inline(always)]
+    pure fn acosh() -> Vec3<T> {
+        Vec3::new(acosh(&self[0]),
+                  acosh(&self[1]),
+                  acosh(&self[2]))
+    }
+
+    #[inline(always)]
+    pure fn atanh() -> Vec3<T> {
+        Vec3::new(atanh(&self[0]),
+                  atanh(&self[1]),
+                  atanh(&self[2]))
+    }
 }
 
 pub impl <T:Copy Hyp> Vec4<T>: Hyp  {
@@ -296,7 +497,7 @@ pub impl <T:Copy Hyp> Vec4<T>: Hyp  {
                   sinh(&self[2]),
                   sinh(&self[3]))
     }
-    
+
     #[inline(always)]
     pure fn cosh() -> Vec4<T> {
         Vec4::new(cosh(&self[0]),
@@ -304,7 +505,7 @@ pub impl <T:Copy Hyp> Vec4<T>: Hyp  {
                   cosh(&self[2]),
                   cosh(&self[3]))
     }
-    
+
     #[inline(always)]
     pure fn tanh() -> Vec4<T> {
         Vec4::new(tanh(&self[0]),
@@ -312,4 +513,28 @@ pub impl <T:Copy Hyp> Vec4<T>: Hyp  {
                   tanh(&self[2]),
                   tanh(&self[3]))
     }
+
+    #[inline(always)]
+    pure fn asinh() -> Vec4<T> {
+        Vec4::new(asinh(&self[0]),
+                  asinh(&self[1]),
+                  asinh(&self[2]),
+                  asinh(&self[3]))
+    }
+
+    #[inline(always)]
+    pure fn acosh() -> Vec4<T> {
+        Vec4::new(acosh(&self[0]),
+                  acosh(&self[1]),
+                  acosh(&self[2]),
+                  acosh(&self[3]))
+    }
+
+    #[inline(always)]
+    pure fn atanh() -> Vec4<T> {
+        Vec4::new(atanh(&self[0]),
+                  atanh(&self[1]),
+                  atanh(&self[2]),
+                  atanh(&self[3]))
+    }
 }
\ No newline at end of file